@@ -1,5 +1,6 @@
 use anyhow::Context;
 use hidapi::{HidApi, HidDevice};
+use nvml_wrapper::{enum_wrappers::device::TemperatureSensor, Nvml};
 use serde::Deserialize;
 use std::fs::read_to_string;
 use std::path::Path;
@@ -15,10 +16,10 @@ const PRODUCT_ID: u16 = 3;
 const REPORT_ID: u8 = 16;
 
 /// Configuration options
-#[derive(Debug, Deserialize, Default)]
-#[serde(default)]
+#[derive(Debug, Deserialize)]
 struct Configuration {
     /// Unit of to use for showing the temperature
+    #[serde(default)]
     temperature_unit: TemperatureUnit,
 
     /// Whether to show a high temperature warning
@@ -29,8 +30,56 @@ struct Configuration {
     #[serde(default = "default_warning_temperature")]
     warning_temperature: f32,
 
-    /// Display mode
+    /// Temperature the warning clears at once triggered, so the warning
+    /// doesn't flicker on/off while hovering right at the threshold.
+    /// Defaults to 85, five degrees below the default `warning_temperature`;
+    /// the default does not follow a configured `warning_temperature`
+    #[serde(default = "default_warning_clear_temperature")]
+    warning_clear_temperature: f32,
+
+    /// Display mode. `Temperature`, `Utilization`, `GpuTemperature` and
+    /// `GpuUtilization` are the single-metric ("basic") modes, each pinning
+    /// the display to one reading; `Automatic` cycles through all of them
+    #[serde(default)]
     display_mode: DisplayMode,
+
+    /// Sensor label patterns checked (in order) to find the CPU temperature,
+    /// the first pattern with at least one matching component is used
+    #[serde(default = "default_temperature_sensors")]
+    temperature_sensors: Vec<String>,
+
+    /// Exponential moving average smoothing factor (alpha) applied to load
+    /// and temperature samples before display. `1.0` disables smoothing and
+    /// reproduces the raw samples
+    #[serde(default = "default_smoothing_factor")]
+    smoothing_factor: f32,
+
+    /// Milliseconds between each display refresh
+    #[serde(default = "default_refresh_interval_ms")]
+    refresh_interval_ms: u64,
+
+    /// Number of frames to show temperature for in `Automatic` mode
+    #[serde(default = "default_temperature_dwell_frames")]
+    temperature_dwell_frames: u32,
+
+    /// Number of frames to show utilization for in `Automatic` mode
+    #[serde(default = "default_utilization_dwell_frames")]
+    utilization_dwell_frames: u32,
+}
+
+impl Default for Configuration {
+    fn default() -> Self {
+        // Deserialize an empty document rather than deriving `Default`, so
+        // this goes through the same per-field `#[serde(default ...)]`
+        // attributes as a real (but empty) configuration file would,
+        // instead of silently falling back to each field type's bare
+        // default (e.g. an empty `temperature_sensors` or a `0.0`
+        // `smoothing_factor`). Every field carries its own `#[serde(default
+        // ...)]` rather than a struct-level `#[serde(default)]`, since the
+        // latter has serde build its baseline via `Self::default()` before
+        // deserializing - which would call straight back into this fn
+        toml::from_str("").expect("empty configuration document must always deserialize")
+    }
 }
 
 // By default warn when temperature reaches 90° celsius
@@ -38,11 +87,45 @@ fn default_warning_temperature() -> f32 {
     90.
 }
 
+// By default clear the warning once temperature drops back below 85° celsius
+fn default_warning_clear_temperature() -> f32 {
+    default_warning_temperature() - 5.0
+}
+
 // By default should show warnings
 fn default_show_warning() -> bool {
     true
 }
 
+// By default smooth fairly aggressively while still tracking real changes
+fn default_smoothing_factor() -> f32 {
+    0.3
+}
+
+// By default refresh the display once a second, matching the old hardcoded cadence
+fn default_refresh_interval_ms() -> u64 {
+    1000
+}
+
+// By default dwell on each metric for 5 frames in `Automatic` mode
+fn default_temperature_dwell_frames() -> u32 {
+    5
+}
+
+fn default_utilization_dwell_frames() -> u32 {
+    5
+}
+
+// Built-in fallback chain of sensor label prefixes, covering Intel and AMD
+fn default_temperature_sensors() -> Vec<String> {
+    vec![
+        "coretemp Package".to_string(),
+        "k10temp Tctl".to_string(),
+        "k10temp Tdie".to_string(),
+        "zenpower".to_string(),
+    ]
+}
+
 // Loads the configuration file
 fn load_configuration() -> anyhow::Result<Configuration> {
     let path = Path::new(CONFIGURATION_PATH);
@@ -81,48 +164,276 @@ fn main() -> anyhow::Result<()> {
         RefreshKind::new().with_cpu(CpuRefreshKind::new().with_cpu_usage()),
     );
 
+    let mut cpu_load_sampler = CpuLoadSampler::new(&mut sys);
+
     let mut components = Components::new();
 
+    // Initialize NVML whenever a GPU phase could appear on the display,
+    // falling back to CPU-only phases when no NVIDIA device/driver is present
+    let wants_gpu = matches!(
+        configuration.display_mode,
+        DisplayMode::GpuTemperature | DisplayMode::GpuUtilization | DisplayMode::Automatic
+    );
+    let nvml = if wants_gpu {
+        match Nvml::init() {
+            Ok(nvml) => Some(nvml),
+            Err(err) => {
+                eprintln!(
+                    "failed to initialize nvml, gpu metrics will be unavailable: {}",
+                    err
+                );
+                None
+            }
+        }
+    } else {
+        None
+    };
+
+    // `GpuTemperature`/`GpuUtilization` never show a CPU reading, so there's
+    // no reason to touch CPU sensors at all in those modes - every other
+    // mode shows a CPU phase (directly, or as part of the `Automatic` cycle).
+    // The exception is a pure GPU mode with no NVML device available at all:
+    // without CPU metrics there, `gpu_metrics`/`last_gpu_metrics` can never
+    // populate and the display would hang on the startup animation forever,
+    // so fall back to showing CPU metrics in the GPU phase's place instead
+    let needs_cpu = !matches!(
+        configuration.display_mode,
+        DisplayMode::GpuTemperature | DisplayMode::GpuUtilization
+    ) || nvml.is_none();
+
     let report_unit = configuration.temperature_unit;
-    let warning_temperature: Temperature =
-        Temperature(report_unit, configuration.warning_temperature);
+    let warning_temperature: Temperature = Temperature(
+        report_unit,
+        (configuration.warning_temperature * 1000.0).round() as i32,
+    );
+    let warning_clear_temperature: Temperature = Temperature(
+        report_unit,
+        (configuration.warning_clear_temperature * 1000.0).round() as i32,
+    );
 
-    let is_automatic = matches!(configuration.display_mode, DisplayMode::Automatic);
+    // Phases cycled through in `Automatic` mode, each with how many frames
+    // to dwell on it; GPU phases are only included when NVML is available.
+    // Other display modes just show their one metric indefinitely
+    let phases: Vec<(MetricPhase, u32)> = match configuration.display_mode {
+        DisplayMode::Temperature => vec![(MetricPhase::CpuTemperature, 1)],
+        DisplayMode::Utilization => vec![(MetricPhase::CpuUtilization, 1)],
+        DisplayMode::GpuTemperature => vec![(MetricPhase::GpuTemperature, 1)],
+        DisplayMode::GpuUtilization => vec![(MetricPhase::GpuUtilization, 1)],
+        DisplayMode::Automatic => {
+            let mut phases = vec![
+                (
+                    MetricPhase::CpuTemperature,
+                    configuration.temperature_dwell_frames,
+                ),
+                (
+                    MetricPhase::CpuUtilization,
+                    configuration.utilization_dwell_frames,
+                ),
+            ];
+
+            if nvml.is_some() {
+                phases.push((
+                    MetricPhase::GpuTemperature,
+                    configuration.temperature_dwell_frames,
+                ));
+                phases.push((
+                    MetricPhase::GpuUtilization,
+                    configuration.utilization_dwell_frames,
+                ));
+            }
+
+            phases
+        }
+    };
+
+    let auto_cycle_frames: u32 = phases.iter().map(|(_, frames)| *frames).sum();
+
+    let refresh_interval = Duration::from_millis(configuration.refresh_interval_ms);
 
     let mut frame_count = 0;
 
+    // Running EMA state, initialized to the first sample of each, tracked
+    // separately per source so cycling phases in `Automatic` mode don't
+    // blend CPU and GPU readings together
+    let mut cpu_load_ema: Option<f32> = None;
+    let mut cpu_temp_ema: Option<Temperature> = None;
+    let mut gpu_load_ema: Option<f32> = None;
+    let mut gpu_temp_ema: Option<Temperature> = None;
+
+    // Last successfully read CPU temperature, used as a fallback when a
+    // frame's reading fails (e.g. a misconfigured sensor label) so a bad
+    // config degrades the display rather than crashing the daemon
+    let mut last_cpu_temp: Option<Temperature> = None;
+
+    // Last successfully read GPU load/temperature, used as this frame's
+    // reading when a pure GPU display mode (no CPU phase to fall back to)
+    // hits a transient read failure
+    let mut last_gpu_metrics: Option<(f32, Temperature)> = None;
+
+    // Tracks whether the warning is currently active, so it only clears
+    // once temperature drops below `warning_clear_temperature` rather than
+    // flickering at `warning_temperature`. Tracked separately per source so
+    // `Automatic` mode cycling through CPU and GPU phases doesn't clear (or
+    // set) one source's warning off the back of the other's temperature
+    let mut cpu_warning_active = false;
+    let mut gpu_warning_active = false;
+
     loop {
-        // Get load and temperature
-        let load = get_cpu_load(&mut sys)?;
-        let cpu_temp = get_cpu_temp(&mut components)?;
+        // Skip collection entirely while asleep/runtime-suspended so we
+        // don't keep sensors (and the devices behind them) woken up
+        if !is_awake(&nvml, needs_cpu, &configuration.temperature_sensors) {
+            std::thread::sleep(refresh_interval);
+            continue;
+        }
 
-        // Determine if warning should be shown
-        let warning = configuration.show_warning && cpu_temp >= warning_temperature;
+        // CPU metrics are skipped entirely in pure `GpuTemperature`/
+        // `GpuUtilization` modes while NVML is available, since nothing
+        // displays them there - every other case (including those modes
+        // without NVML, see `needs_cpu`) gathers them, cheap as they are,
+        // both for their own phase and as the fallback for a GPU phase that fails to read
+        // this frame. A failed temperature read (e.g. no sensor matched any
+        // configured pattern) reuses the last known reading instead of
+        // crashing the daemon, same as how GPU read failures are handled
+        // below
+        let cpu_metrics = if needs_cpu {
+            let cpu_load = cpu_load_sampler.sample(&mut sys);
+            let cpu_temp = match get_cpu_temp(&mut components, &configuration.temperature_sensors)
+            {
+                Ok(temp) => {
+                    last_cpu_temp = Some(temp);
+                    temp
+                }
+                Err(err) => match last_cpu_temp {
+                    Some(temp) => {
+                        eprintln!(
+                            "failed to read cpu temperature, reusing last known reading: {}",
+                            err
+                        );
+                        temp
+                    }
+                    None => {
+                        eprintln!("failed to read cpu temperature, skipping frame: {}", err);
+                        std::thread::sleep(refresh_interval);
+                        continue;
+                    }
+                },
+            };
+
+            let cpu_temp =
+                smooth_temp(&mut cpu_temp_ema, cpu_temp, configuration.smoothing_factor);
+            update_warning_hysteresis(
+                &mut cpu_warning_active,
+                cpu_temp,
+                warning_temperature,
+                warning_clear_temperature,
+            );
+
+            Some((
+                smooth_load(&mut cpu_load_ema, cpu_load, configuration.smoothing_factor),
+                cpu_temp,
+            ))
+        } else {
+            None
+        };
+
+        // GPU metrics are best-effort: any failure (including no device
+        // being present) just means GPU phases fall back to CPU this frame
+        // (or, in a pure GPU display mode with no CPU data to fall back to,
+        // the last known GPU reading) rather than the whole daemon crashing
+        let gpu_metrics = nvml.as_ref().and_then(|nvml| {
+            match (get_gpu_load(nvml), get_gpu_temp(nvml)) {
+                (Ok(load), Ok(temp)) => Some((load, temp)),
+                (Err(err), _) | (_, Err(err)) => {
+                    eprintln!("failed to read gpu metrics: {}", err);
+                    None
+                }
+            }
+        });
+        let gpu_metrics = gpu_metrics.map(|(load, temp)| {
+            let temp = smooth_temp(&mut gpu_temp_ema, temp, configuration.smoothing_factor);
+            update_warning_hysteresis(
+                &mut gpu_warning_active,
+                temp,
+                warning_temperature,
+                warning_clear_temperature,
+            );
+
+            (
+                smooth_load(&mut gpu_load_ema, load, configuration.smoothing_factor),
+                temp,
+            )
+        });
+        if gpu_metrics.is_some() {
+            last_gpu_metrics = gpu_metrics;
+        }
+
+        // Select the current phase of the cycle (always the single
+        // configured phase outside of `Automatic` mode)
+        let phase = phase_at(&phases, frame_count);
+
+        let (is_temperature, load, temp) = match phase {
+            MetricPhase::CpuTemperature => match cpu_metrics {
+                Some((load, temp)) => (true, load, temp),
+                None => {
+                    std::thread::sleep(refresh_interval);
+                    continue;
+                }
+            },
+            MetricPhase::CpuUtilization => match cpu_metrics {
+                Some((load, temp)) => (false, load, temp),
+                None => {
+                    std::thread::sleep(refresh_interval);
+                    continue;
+                }
+            },
+            MetricPhase::GpuTemperature => match gpu_metrics.or(cpu_metrics).or(last_gpu_metrics) {
+                Some((load, temp)) => (true, load, temp),
+                None => {
+                    std::thread::sleep(refresh_interval);
+                    continue;
+                }
+            },
+            MetricPhase::GpuUtilization => match gpu_metrics.or(cpu_metrics).or(last_gpu_metrics) {
+                Some((load, temp)) => (false, load, temp),
+                None => {
+                    std::thread::sleep(refresh_interval);
+                    continue;
+                }
+            },
+        };
+
+        // Pick this frame's warning state from the phase's own source, not
+        // whichever temperature ends up displayed - a GPU phase falling back
+        // to a CPU (or stale GPU) reading above must still reflect the GPU's
+        // own hysteresis state, not get toggled by a reading from elsewhere
+        let warning_active = match phase {
+            MetricPhase::CpuTemperature | MetricPhase::CpuUtilization => cpu_warning_active,
+            MetricPhase::GpuTemperature | MetricPhase::GpuUtilization => gpu_warning_active,
+        };
+        let warning = configuration.show_warning && warning_active;
 
         // Convert the load percent to 1-10 for the square usage indicator
         let load_progress = ((load / 100.0) * 10.0).clamp(1.0, 10.0) as u8;
 
         // Convert to chosen unit type
-        let cpu_temp_local = cpu_temp.convert(report_unit);
-        let cpu_temp_value = Into::<u32>::into(cpu_temp_local) as u16;
+        let temp_local = temp.convert(report_unit);
+        let temp_value = Into::<u32>::into(temp_local) as u16;
 
         // Clamp load value for display
         let load_value = load.clamp(0., 999.) as u16;
 
-        // Determine control unit for the temperature
+        // Determine control unit for the temperature. The device protocol
+        // has no separate "this is a GPU reading" byte, so CPU and GPU
+        // temperatures share the same Celsius/Fahrenheit control units
         let control_unit = ControlUnit::from(report_unit);
 
-        // Check if we are displaying temperature
-        let is_temperature = (is_automatic && frame_count < 5)
-            || matches!(configuration.display_mode, DisplayMode::Temperature);
-
         if is_temperature {
             // Write the temperature state to the device
             write_device_state(
                 &mut device,
                 control_unit,
                 load_progress,
-                cpu_temp_value,
+                temp_value,
                 warning,
             )?;
         } else {
@@ -137,46 +448,95 @@ fn main() -> anyhow::Result<()> {
         }
 
         // Wait
-        std::thread::sleep(Duration::from_secs(1));
+        std::thread::sleep(refresh_interval);
 
         frame_count += 1;
 
-        // Reset on 11th frame
-        if frame_count == 10 {
+        // Reset once a full `Automatic`-mode cycle has elapsed
+        if frame_count == auto_cycle_frames {
             frame_count = 0;
         }
     }
 }
 
-/// Obtains the CPU temperature
-fn get_cpu_temp(components: &mut Components) -> anyhow::Result<Temperature> {
+/// Looks up which phase of an `Automatic`-mode cycle a frame count falls in
+fn phase_at(phases: &[(MetricPhase, u32)], frame_count: u32) -> MetricPhase {
+    let mut remaining = frame_count;
+
+    for (phase, frames) in phases {
+        if remaining < *frames {
+            return *phase;
+        }
+
+        remaining -= frames;
+    }
+
+    phases[0].0
+}
+
+/// Obtains the CPU temperature, trying each sensor label pattern in order
+/// until one matches at least one component
+fn get_cpu_temp(components: &mut Components, sensors: &[String]) -> anyhow::Result<Temperature> {
     components.refresh_list();
 
-    // Take average of all available packages
-    let mut total_temps = 0;
-    let mut total_temp = 0.0;
+    for pattern in sensors {
+        // Take average of all components matching this pattern, in
+        // millidegrees so the average doesn't accumulate float error
+        let mut total_temps = 0;
+        let mut total_temp_millidegrees = 0;
 
-    for component in components {
-        let label = component.label();
-        let temp = component.temperature();
+        for component in components.iter() {
+            let label = component.label();
+            let temp_millidegrees = (component.temperature() * 1000.0).round() as i32;
 
-        // Intel CPU package
-        if label.starts_with("coretemp Package") {
-            total_temp += temp;
-            total_temps += 1;
+            if label.starts_with(pattern.as_str()) {
+                total_temp_millidegrees += temp_millidegrees;
+                total_temps += 1;
+            }
+        }
+
+        if total_temps > 0 {
+            let avg = total_temp_millidegrees / total_temps;
+            return Ok(Temperature(TemperatureUnit::Celsius, avg));
         }
     }
 
-    let avg = total_temp / (total_temps as f32);
+    anyhow::bail!("no matching temperature sensor found")
+}
 
-    Ok(Temperature(TemperatureUnit::Celsius, avg))
+/// Tracks CPU usage while respecting sysinfo's minimum refresh interval,
+/// decoupling load aggregation from the display's own refresh cadence
+struct CpuLoadSampler {
+    last_refresh: std::time::Instant,
+    last_value: f32,
 }
 
-/// Obtains the CPU load, sleeps for 1s to allow time to aggregate the load
-/// information, this is required.
-fn get_cpu_load(sys: &mut System) -> anyhow::Result<f32> {
-    sys.refresh_cpu_usage(); // Refreshing CPU information.
+impl CpuLoadSampler {
+    fn new(sys: &mut System) -> Self {
+        sys.refresh_cpu_usage();
 
+        Self {
+            last_refresh: std::time::Instant::now(),
+            last_value: average_cpu_usage(sys),
+        }
+    }
+
+    /// Samples the current CPU load, only asking sysinfo to refresh once
+    /// its minimum sampling interval has elapsed, reusing the last known
+    /// value otherwise
+    fn sample(&mut self, sys: &mut System) -> f32 {
+        if self.last_refresh.elapsed() >= sysinfo::MINIMUM_CPU_UPDATE_INTERVAL {
+            sys.refresh_cpu_usage();
+            self.last_value = average_cpu_usage(sys);
+            self.last_refresh = std::time::Instant::now();
+        }
+
+        self.last_value
+    }
+}
+
+/// Averages the usage of all known CPUs
+fn average_cpu_usage(sys: &System) -> f32 {
     let mut total_cpus = 0;
     let mut total_usage = 0.0;
 
@@ -187,22 +547,173 @@ fn get_cpu_load(sys: &mut System) -> anyhow::Result<f32> {
         total_cpus += 1;
     }
 
-    let avg = total_usage / (total_cpus as f32);
+    total_usage / (total_cpus as f32)
+}
+
+/// Applies an exponential moving average to a load sample, seeding the
+/// running state with the first sample seen
+fn smooth_load(state: &mut Option<f32>, sample: f32, alpha: f32) -> f32 {
+    let smoothed = match *state {
+        Some(previous) => alpha * sample + (1.0 - alpha) * previous,
+        None => sample,
+    };
+
+    *state = Some(smoothed);
+
+    smoothed
+}
+
+/// Applies an exponential moving average to a temperature sample, seeding
+/// the running state with the first sample seen
+fn smooth_temp(state: &mut Option<Temperature>, sample: Temperature, alpha: f32) -> Temperature {
+    let sample = sample.convert(TemperatureUnit::Celsius);
+
+    let smoothed = match *state {
+        Some(previous) => {
+            let previous = previous.convert(TemperatureUnit::Celsius);
+            let value = alpha * sample.1 as f32 + (1.0 - alpha) * previous.1 as f32;
+            Temperature(TemperatureUnit::Celsius, value.round() as i32)
+        }
+        None => sample,
+    };
+
+    *state = Some(smoothed);
+
+    smoothed
+}
+
+/// Updates a source's warning hysteresis flag in place for a freshly read
+/// temperature, only clearing once it drops below `warning_clear_temperature`
+/// rather than flickering right at `warning_temperature`
+fn update_warning_hysteresis(
+    active: &mut bool,
+    temp: Temperature,
+    warning_temperature: Temperature,
+    warning_clear_temperature: Temperature,
+) {
+    if temp >= warning_temperature {
+        *active = true;
+    } else if temp < warning_clear_temperature {
+        *active = false;
+    }
+}
+
+/// Checks whether the machine appears to be in an active power state (D0)
+/// before polling sensors, skipping collection while suspended or
+/// runtime-suspended so the daemon doesn't keep devices awake
+fn is_awake(nvml: &Option<Nvml>, needs_cpu: bool, temperature_sensors: &[String]) -> bool {
+    // Querying a runtime-suspended GPU over NVML wakes it, so check the
+    // backing PCI device's power state first
+    if let Some(nvml) = nvml {
+        if let Ok(device) = nvml.device_by_index(0) {
+            if let Ok(pci_info) = device.pci_info() {
+                let path = format!(
+                    "/sys/bus/pci/devices/{}/power/runtime_status",
+                    pci_info.bus_id
+                );
+
+                if is_runtime_suspended(&path) {
+                    return false;
+                }
+            }
+        }
+    }
+
+    // CPU temperature sensors are backed by hwmon chips (coretemp, k10temp,
+    // zenpower, ...), which may themselves be runtime-suspended on their
+    // platform/PCI/i2c parent device; reading one would wake that device.
+    // Only the chip(s) backing a configured sensor pattern are actually
+    // polled, so only check those - scanning every hwmon chip picked up
+    // unrelated suspended hardware (e.g. an NVMe drive's hwmon entry) and
+    // reported asleep when the machine wasn't. Skipped whenever the main
+    // loop isn't going to read CPU sensors this run either (`needs_cpu`)
+    if needs_cpu {
+        if let Ok(entries) = std::fs::read_dir("/sys/class/hwmon") {
+            for entry in entries.flatten() {
+                let chip_name = match read_to_string(entry.path().join("name")) {
+                    Ok(name) => name,
+                    Err(_) => continue,
+                };
+                let chip_name = chip_name.trim();
+
+                let backs_configured_sensor = temperature_sensors
+                    .iter()
+                    .any(|pattern| pattern.starts_with(chip_name));
+
+                if !backs_configured_sensor {
+                    continue;
+                }
+
+                let path = entry.path().join("device/power/runtime_status");
+
+                if is_runtime_suspended(&path.to_string_lossy()) {
+                    return false;
+                }
+            }
+        }
+    }
 
-    Ok(avg)
+    true
+}
+
+/// Reads a `power/runtime_status` sysfs attribute, treating a missing file
+/// (the device has no runtime PM support) or any value other than
+/// `"suspended"` as awake
+fn is_runtime_suspended(path: &str) -> bool {
+    read_to_string(path)
+        .map(|status| status.trim() == "suspended")
+        .unwrap_or(false)
+}
+
+/// Obtains the temperature of the first NVIDIA GPU
+fn get_gpu_temp(nvml: &Nvml) -> anyhow::Result<Temperature> {
+    let device = nvml.device_by_index(0).context("failed to get gpu device")?;
+    let temp = device
+        .temperature(TemperatureSensor::Gpu)
+        .context("failed to read gpu temperature")?;
+
+    Ok(Temperature(TemperatureUnit::Celsius, temp as i32 * 1000))
 }
 
+/// Obtains the utilization of the first NVIDIA GPU
+fn get_gpu_load(nvml: &Nvml) -> anyhow::Result<f32> {
+    let device = nvml.device_by_index(0).context("failed to get gpu device")?;
+    let utilization = device
+        .utilization_rates()
+        .context("failed to read gpu utilization")?;
+
+    Ok(utilization.gpu as f32)
+}
+
+/// The four single-metric variants each act as a "basic" mode, pinning the
+/// display to one reading; `Automatic` is the only mode that cycles
 #[derive(Debug, Clone, Copy, Deserialize, Default)]
 enum DisplayMode {
-    // Show temperature as the main focus
+    // Show CPU temperature as the main focus
     #[default]
     Temperature,
     // Show CPU utilization as the main focus
     Utilization,
-    // Switch between temps and utilization
+    // Show GPU temperature as the main focus
+    GpuTemperature,
+    // Show GPU utilization as the main focus
+    GpuUtilization,
+    // Cycle through CPU temperature, CPU utilization, GPU temperature and
+    // GPU utilization; GPU phases are left out when NVML is unavailable, and
+    // show the CPU reading on frames where the GPU can't be read
     Automatic,
 }
 
+/// A single metric shown on the display, combining which hardware it comes
+/// from with which reading it is
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MetricPhase {
+    CpuTemperature,
+    CpuUtilization,
+    GpuTemperature,
+    GpuUtilization,
+}
+
 /// Unit of temperature
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Default)]
 enum TemperatureUnit {
@@ -213,19 +724,21 @@ enum TemperatureUnit {
     Fahrenheit,
 }
 
-/// Represents a temperature in a specific unit
+/// Represents a temperature in a specific unit, stored as millidegrees to
+/// avoid `f32` rounding artifacts accumulating across averaging/smoothing
 #[derive(Debug, Clone, Copy)]
-struct Temperature(TemperatureUnit, f32);
+struct Temperature(TemperatureUnit, i32);
 
 impl Temperature {
-    /// Converts the temperature to the provided unit
+    /// Converts the temperature to the provided unit using integer
+    /// millidegree arithmetic
     pub fn convert(self, unit: TemperatureUnit) -> Self {
         match (self.0, unit) {
             (TemperatureUnit::Celsius, TemperatureUnit::Fahrenheit) => {
-                Self(unit, self.1 * 9.0 / 5.0 + 32.0)
+                Self(unit, self.1 * 9 / 5 + 32000)
             }
             (TemperatureUnit::Fahrenheit, TemperatureUnit::Celsius) => {
-                Self(unit, (self.1 - 32.0) * 5.0 / 9.0)
+                Self(unit, (self.1 - 32000) * 5 / 9)
             }
 
             // No conversion needed
@@ -237,7 +750,12 @@ impl Temperature {
 
 impl From<Temperature> for u32 {
     fn from(value: Temperature) -> Self {
-        value.1.round() as u32
+        // Clamp negative values to 0 (matching the saturating f32 -> u32
+        // cast this replaced) before rounding to the nearest whole degree,
+        // since a plain `i32 as u32` would instead wrap around
+        let millidegrees = value.1.max(0);
+
+        ((millidegrees + 500) / 1000) as u32
     }
 }
 